@@ -0,0 +1,491 @@
+//!Parsing and serialization for CSS-style color strings, such as the ones
+//!found in stylesheets or user-facing theme configuration.
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use {Rgb, Hsl, Lab, Lch, Xyz, clamp};
+use white_point::D65;
+
+///An error returned when a string doesn't match any of the supported CSS
+///color forms.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseColorError {
+    message: String,
+}
+
+impl ParseColorError {
+    fn new<S: Into<String>>(message: S) -> ParseColorError {
+        ParseColorError { message: message.into() }
+    }
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ParseColorError {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+impl FromStr for Rgb<f32> {
+    type Err = ParseColorError;
+
+    ///Parses a CSS color string into an `Rgb` value. Accepts `#rgb`,
+    ///`#rrggbb`, `#rrggbbaa` hex, `rgb()`/`rgba()`, `hsl()`/`hsla()`,
+    ///`lab()`/`lch()`, and the CSS/SVG named colors.
+    fn from_str(s: &str) -> Result<Rgb<f32>, ParseColorError> {
+        parse_color(s)
+    }
+}
+
+///Parses a CSS color string into an `Rgb` value. Accepts `#rgb`,
+///`#rrggbb`, `#rrggbbaa` hex, `rgb()`/`rgba()`, `hsl()`/`hsla()`,
+///`lab()`/`lch()`, and the CSS/SVG named colors.
+pub fn parse_color(input: &str) -> Result<Rgb<f32>, ParseColorError> {
+    let s = input.trim();
+
+    if s.starts_with('#') {
+        return parse_hex(&s[1..]);
+    }
+
+    if let Some(args) = strip_function(s, "rgba").or_else(|| strip_function(s, "rgb")) {
+        return parse_rgb_args(args);
+    }
+
+    if let Some(args) = strip_function(s, "hsla").or_else(|| strip_function(s, "hsl")) {
+        return parse_hsl_args(args);
+    }
+
+    if let Some(args) = strip_function(s, "lab") {
+        return parse_lab_args(args);
+    }
+
+    if let Some(args) = strip_function(s, "lch") {
+        return parse_lch_args(args);
+    }
+
+    if s.eq_ignore_ascii_case("transparent") {
+        return Ok(rgba8_to_rgb(0, 0, 0, 0));
+    }
+
+    named_color(s).ok_or_else(|| ParseColorError::new(format!("unrecognized color: {}", input)))
+}
+
+///Strips `name(...)` down to the inside of the parentheses, ignoring case.
+fn strip_function<'a>(s: &'a str, name: &str) -> Option<&'a str> {
+    //`get` returns `None` both when `s` is too short and when `name.len()`
+    //would land inside a multi-byte character, so this never panics on
+    //non-ASCII input.
+    let prefix = s.get(..name.len())?;
+    if !prefix.eq_ignore_ascii_case(name) {
+        return None;
+    }
+
+    let rest = s[name.len()..].trim();
+    if rest.starts_with('(') && rest.ends_with(')') {
+        Some(&rest[1..rest.len() - 1])
+    } else {
+        None
+    }
+}
+
+///Splits the inside of a `rgb()`/`hsl()`/`lab()`/`lch()` call into its
+///components. Accepts both the legacy comma-separated syntax
+///(`rgb(255, 0, 0)`) and the modern space-separated syntax with an optional
+///`/` before the alpha component (`rgb(255 0 0 / 50%)`).
+fn split_args(args: &str) -> Vec<String> {
+    args.split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect()
+}
+
+fn parse_percent_or_u8(s: &str) -> Result<f32, ParseColorError> {
+    if s.ends_with('%') {
+        let v: f32 = s[..s.len() - 1]
+            .parse()
+            .map_err(|_| ParseColorError::new(format!("invalid percentage: {}", s)))?;
+        Ok(v / 100.0)
+    } else {
+        let v: f32 = s.parse().map_err(|_| ParseColorError::new(format!("invalid number: {}", s)))?;
+        Ok(v / 255.0)
+    }
+}
+
+fn parse_alpha(s: &str) -> Result<f32, ParseColorError> {
+    if s.ends_with('%') {
+        let v: f32 = s[..s.len() - 1]
+            .parse()
+            .map_err(|_| ParseColorError::new(format!("invalid alpha: {}", s)))?;
+        Ok(v / 100.0)
+    } else {
+        s.parse().map_err(|_| ParseColorError::new(format!("invalid alpha: {}", s)))
+    }
+}
+
+fn parse_rgb_args(args: &str) -> Result<Rgb<f32>, ParseColorError> {
+    let parts = split_args(args);
+    if parts.len() != 3 && parts.len() != 4 {
+        return Err(ParseColorError::new("rgb()/rgba() takes 3 or 4 components"));
+    }
+
+    let red = parse_percent_or_u8(&parts[0])?;
+    let green = parse_percent_or_u8(&parts[1])?;
+    let blue = parse_percent_or_u8(&parts[2])?;
+    let alpha = if parts.len() == 4 { parse_alpha(&parts[3])? } else { 1.0 };
+
+    Ok(Rgb::rgba(red, green, blue, alpha))
+}
+
+fn parse_hsl_args(args: &str) -> Result<Rgb<f32>, ParseColorError> {
+    let parts = split_args(args);
+    if parts.len() != 3 && parts.len() != 4 {
+        return Err(ParseColorError::new("hsl()/hsla() takes 3 or 4 components"));
+    }
+
+    let hue_str = parts[0].trim_end_matches("deg");
+    let hue: f32 = hue_str
+        .parse()
+        .map_err(|_| ParseColorError::new(format!("invalid hue: {}", parts[0])))?;
+    let saturation: f32 = parts[1]
+        .trim_end_matches('%')
+        .parse()
+        .map_err(|_| ParseColorError::new(format!("invalid saturation: {}", parts[1])))
+        .map(|v: f32| v / 100.0)?;
+    let lightness = parts[2]
+        .trim_end_matches('%')
+        .parse::<f32>()
+        .map_err(|_| ParseColorError::new(format!("invalid lightness: {}", parts[2])))
+        .map(|v| v / 100.0)?;
+    let alpha = if parts.len() == 4 { parse_alpha(&parts[3])? } else { 1.0 };
+
+    let hsl = Hsl::hsla(hue, saturation, lightness, alpha);
+    Ok(Rgb::from(hsl))
+}
+
+fn parse_lab_args(args: &str) -> Result<Rgb<f32>, ParseColorError> {
+    let parts = split_args(args);
+    if parts.len() != 3 && parts.len() != 4 {
+        return Err(ParseColorError::new("lab() takes 3 or 4 components"));
+    }
+
+    let l: f32 = parts[0].trim_end_matches('%').parse().map_err(|_| ParseColorError::new(format!("invalid L: {}", parts[0])))?;
+    let a: f32 = parts[1].parse().map_err(|_| ParseColorError::new(format!("invalid a: {}", parts[1])))?;
+    let b: f32 = parts[2].parse().map_err(|_| ParseColorError::new(format!("invalid b: {}", parts[2])))?;
+    let alpha = if parts.len() == 4 { parse_alpha(&parts[3])? } else { 1.0 };
+
+    let lab = Lab::laba(l / 100.0, a / 128.0, b / 128.0, alpha);
+    let xyz: Xyz<f32, D65> = Xyz::from(lab);
+    Ok(Rgb::from(xyz))
+}
+
+fn parse_lch_args(args: &str) -> Result<Rgb<f32>, ParseColorError> {
+    let parts = split_args(args);
+    if parts.len() != 3 && parts.len() != 4 {
+        return Err(ParseColorError::new("lch() takes 3 or 4 components"));
+    }
+
+    let l: f32 = parts[0].trim_end_matches('%').parse().map_err(|_| ParseColorError::new(format!("invalid L: {}", parts[0])))?;
+    let c: f32 = parts[1].parse().map_err(|_| ParseColorError::new(format!("invalid chroma: {}", parts[1])))?;
+    let h: f32 = parts[2].trim_end_matches("deg").parse().map_err(|_| ParseColorError::new(format!("invalid hue: {}", parts[2])))?;
+    let alpha = if parts.len() == 4 { parse_alpha(&parts[3])? } else { 1.0 };
+
+    let lch = Lch::lcha(l / 100.0, c / 128.0, h, alpha);
+    let xyz: Xyz<f32, D65> = Xyz::from(lch);
+    Ok(Rgb::from(xyz))
+}
+
+fn parse_hex(hex: &str) -> Result<Rgb<f32>, ParseColorError> {
+    //Reject non-hex-digit (including non-ASCII) input up front, so the byte
+    //slicing below never lands outside a char boundary.
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(ParseColorError::new(format!("invalid hex color: #{}", hex)));
+    }
+
+    let expand = |c: char| -> Result<u8, ParseColorError> {
+        u8::from_str_radix(&format!("{0}{0}", c), 16)
+            .map_err(|_| ParseColorError::new(format!("invalid hex digit: {}", c)))
+    };
+
+    match hex.len() {
+        3 => {
+            let mut chars = hex.chars();
+            let r = expand(chars.next().unwrap())?;
+            let g = expand(chars.next().unwrap())?;
+            let b = expand(chars.next().unwrap())?;
+            Ok(rgba8_to_rgb(r, g, b, 255))
+        }
+        6 | 8 => {
+            let channel = |i: usize| -> Result<u8, ParseColorError> {
+                u8::from_str_radix(&hex[i..i + 2], 16)
+                    .map_err(|_| ParseColorError::new(format!("invalid hex in: {}", hex)))
+            };
+
+            let r = channel(0)?;
+            let g = channel(2)?;
+            let b = channel(4)?;
+            let a = if hex.len() == 8 { channel(6)? } else { 255 };
+            Ok(rgba8_to_rgb(r, g, b, a))
+        }
+        _ => Err(ParseColorError::new(format!("hex colors must have 3, 6 or 8 digits, got {}", hex.len()))),
+    }
+}
+
+fn rgba8_to_rgb(r: u8, g: u8, b: u8, a: u8) -> Rgb<f32> {
+    Rgb::rgba(
+        r as f32 / 255.0,
+        g as f32 / 255.0,
+        b as f32 / 255.0,
+        a as f32 / 255.0,
+    )
+}
+
+///Quantizes a channel in the range `[0.0, 1.0]` to a `u8`, rounding to the
+///nearest value.
+fn to_u8(c: f32) -> u8 {
+    (clamp(c, 0.0, 1.0) * 255.0 + 0.5) as u8
+}
+
+impl Rgb<f32> {
+    ///Converts the color to its 8-bit-per-channel `(red, green, blue, alpha)`
+    ///representation, rounding each channel to the nearest `u8`.
+    pub fn to_rgba8(&self) -> (u8, u8, u8, u8) {
+        (to_u8(self.red), to_u8(self.green), to_u8(self.blue), to_u8(self.alpha))
+    }
+
+    ///Formats the color as a `#rrggbb` hex string, or `#rrggbbaa` when the
+    ///color isn't fully opaque.
+    pub fn to_hex(&self) -> String {
+        let (r, g, b, a) = self.to_rgba8();
+        if a == 255 {
+            format!("#{:02x}{:02x}{:02x}", r, g, b)
+        } else {
+            format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+        }
+    }
+}
+
+macro_rules! named_colors {
+    ($($name:expr => ($r:expr, $g:expr, $b:expr)),* $(,)*) => {
+        fn named_color(name: &str) -> Option<Rgb<f32>> {
+            match name.to_ascii_lowercase().as_str() {
+                $($name => Some(rgba8_to_rgb($r, $g, $b, 255)),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+named_colors! {
+    "black" => (0, 0, 0),
+    "silver" => (192, 192, 192),
+    "gray" => (128, 128, 128),
+    "grey" => (128, 128, 128),
+    "white" => (255, 255, 255),
+    "maroon" => (128, 0, 0),
+    "red" => (255, 0, 0),
+    "purple" => (128, 0, 128),
+    "fuchsia" => (255, 0, 255),
+    "magenta" => (255, 0, 255),
+    "green" => (0, 128, 0),
+    "lime" => (0, 255, 0),
+    "olive" => (128, 128, 0),
+    "yellow" => (255, 255, 0),
+    "navy" => (0, 0, 128),
+    "blue" => (0, 0, 255),
+    "teal" => (0, 128, 128),
+    "aqua" => (0, 255, 255),
+    "cyan" => (0, 255, 255),
+    "orange" => (255, 165, 0),
+    "aliceblue" => (240, 248, 255),
+    "antiquewhite" => (250, 235, 215),
+    "aquamarine" => (127, 255, 212),
+    "azure" => (240, 255, 255),
+    "beige" => (245, 245, 220),
+    "bisque" => (255, 228, 196),
+    "blanchedalmond" => (255, 235, 205),
+    "blueviolet" => (138, 43, 226),
+    "brown" => (165, 42, 42),
+    "burlywood" => (222, 184, 135),
+    "cadetblue" => (95, 158, 160),
+    "chartreuse" => (127, 255, 0),
+    "chocolate" => (210, 105, 30),
+    "coral" => (255, 127, 80),
+    "cornflowerblue" => (100, 149, 237),
+    "cornsilk" => (255, 248, 220),
+    "crimson" => (220, 20, 60),
+    "darkblue" => (0, 0, 139),
+    "darkcyan" => (0, 139, 139),
+    "darkgoldenrod" => (184, 134, 11),
+    "darkgray" => (169, 169, 169),
+    "darkgreen" => (0, 100, 0),
+    "darkgrey" => (169, 169, 169),
+    "darkkhaki" => (189, 183, 107),
+    "darkmagenta" => (139, 0, 139),
+    "darkolivegreen" => (85, 107, 47),
+    "darkorange" => (255, 140, 0),
+    "darkorchid" => (153, 50, 204),
+    "darkred" => (139, 0, 0),
+    "darksalmon" => (233, 150, 122),
+    "darkseagreen" => (143, 188, 143),
+    "darkslateblue" => (72, 61, 139),
+    "darkslategray" => (47, 79, 79),
+    "darkslategrey" => (47, 79, 79),
+    "darkturquoise" => (0, 206, 209),
+    "darkviolet" => (148, 0, 211),
+    "deeppink" => (255, 20, 147),
+    "deepskyblue" => (0, 191, 255),
+    "dimgray" => (105, 105, 105),
+    "dimgrey" => (105, 105, 105),
+    "dodgerblue" => (30, 144, 255),
+    "firebrick" => (178, 34, 34),
+    "floralwhite" => (255, 250, 240),
+    "forestgreen" => (34, 139, 34),
+    "gainsboro" => (220, 220, 220),
+    "ghostwhite" => (248, 248, 255),
+    "gold" => (255, 215, 0),
+    "goldenrod" => (218, 165, 32),
+    "greenyellow" => (173, 255, 47),
+    "honeydew" => (240, 255, 240),
+    "hotpink" => (255, 105, 180),
+    "indianred" => (205, 92, 92),
+    "indigo" => (75, 0, 130),
+    "ivory" => (255, 255, 240),
+    "khaki" => (240, 230, 140),
+    "lavender" => (230, 230, 250),
+    "lavenderblush" => (255, 240, 245),
+    "lawngreen" => (124, 252, 0),
+    "lemonchiffon" => (255, 250, 205),
+    "lightblue" => (173, 216, 230),
+    "lightcoral" => (240, 128, 128),
+    "lightcyan" => (224, 255, 255),
+    "lightgoldenrodyellow" => (250, 250, 210),
+    "lightgray" => (211, 211, 211),
+    "lightgreen" => (144, 238, 144),
+    "lightgrey" => (211, 211, 211),
+    "lightpink" => (255, 182, 193),
+    "lightsalmon" => (255, 160, 122),
+    "lightseagreen" => (32, 178, 170),
+    "lightskyblue" => (135, 206, 250),
+    "lightslategray" => (119, 136, 153),
+    "lightslategrey" => (119, 136, 153),
+    "lightsteelblue" => (176, 196, 222),
+    "lightyellow" => (255, 255, 224),
+    "limegreen" => (50, 205, 50),
+    "linen" => (250, 240, 230),
+    "mediumaquamarine" => (102, 205, 170),
+    "mediumblue" => (0, 0, 205),
+    "mediumorchid" => (186, 85, 211),
+    "mediumpurple" => (147, 112, 219),
+    "mediumseagreen" => (60, 179, 113),
+    "mediumslateblue" => (123, 104, 238),
+    "mediumspringgreen" => (0, 250, 154),
+    "mediumturquoise" => (72, 209, 204),
+    "mediumvioletred" => (199, 21, 133),
+    "midnightblue" => (25, 25, 112),
+    "mintcream" => (245, 255, 250),
+    "mistyrose" => (255, 228, 225),
+    "moccasin" => (255, 228, 181),
+    "navajowhite" => (255, 222, 173),
+    "oldlace" => (253, 245, 230),
+    "olivedrab" => (107, 142, 35),
+    "orangered" => (255, 69, 0),
+    "orchid" => (218, 112, 214),
+    "palegoldenrod" => (238, 232, 170),
+    "palegreen" => (152, 251, 152),
+    "paleturquoise" => (175, 238, 238),
+    "palevioletred" => (219, 112, 147),
+    "papayawhip" => (255, 239, 213),
+    "peachpuff" => (255, 218, 185),
+    "peru" => (205, 133, 63),
+    "pink" => (255, 192, 203),
+    "plum" => (221, 160, 221),
+    "powderblue" => (176, 224, 230),
+    "rosybrown" => (188, 143, 143),
+    "royalblue" => (65, 105, 225),
+    "saddlebrown" => (139, 69, 19),
+    "salmon" => (250, 128, 114),
+    "sandybrown" => (244, 164, 96),
+    "seagreen" => (46, 139, 87),
+    "seashell" => (255, 245, 238),
+    "sienna" => (160, 82, 45),
+    "skyblue" => (135, 206, 235),
+    "slateblue" => (106, 90, 205),
+    "slategray" => (112, 128, 144),
+    "slategrey" => (112, 128, 144),
+    "snow" => (255, 250, 250),
+    "springgreen" => (0, 255, 127),
+    "steelblue" => (70, 130, 180),
+    "tan" => (210, 180, 140),
+    "thistle" => (216, 191, 216),
+    "tomato" => (255, 99, 71),
+    "turquoise" => (64, 224, 208),
+    "violet" => (238, 130, 238),
+    "wheat" => (245, 222, 179),
+    "whitesmoke" => (245, 245, 245),
+    "yellowgreen" => (154, 205, 50),
+    "rebeccapurple" => (102, 51, 153),
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_color;
+    use Rgb;
+
+    #[test]
+    fn hex_forms() {
+        assert_eq!(parse_color("#f00").unwrap(), Rgb::rgb(1.0, 0.0, 0.0));
+        assert_eq!(parse_color("#ff0000").unwrap(), Rgb::rgb(1.0, 0.0, 0.0));
+        assert_eq!(parse_color("#ff000080").unwrap().to_rgba8(), (255, 0, 0, 128));
+    }
+
+    #[test]
+    fn rgb_comma_and_space_forms() {
+        let comma = parse_color("rgb(255, 0, 0)").unwrap();
+        let space = parse_color("rgb(255 0 0)").unwrap();
+        assert_eq!(comma, space);
+        assert_eq!(comma.to_rgba8(), (255, 0, 0, 255));
+    }
+
+    #[test]
+    fn rgba_with_slash_alpha() {
+        let c = parse_color("rgba(255, 0, 0, 0.5)").unwrap();
+        let c2 = parse_color("rgb(255 0 0 / 0.5)").unwrap();
+        assert_eq!(c.to_rgba8(), c2.to_rgba8());
+        assert_eq!(c.to_rgba8(), (255, 0, 0, 128));
+    }
+
+    #[test]
+    fn named_colors() {
+        assert_eq!(parse_color("red").unwrap().to_rgba8(), (255, 0, 0, 255));
+        assert_eq!(parse_color("RebeccaPurple").unwrap().to_rgba8(), (102, 51, 153, 255));
+        assert_eq!(parse_color("transparent").unwrap().to_rgba8(), (0, 0, 0, 0));
+    }
+
+    #[test]
+    fn unrecognized_is_an_error() {
+        assert!(parse_color("not-a-color").is_err());
+    }
+
+    #[test]
+    fn non_ascii_input_errors_instead_of_panicking() {
+        assert!(parse_color("#€€").is_err());
+        // The € here lands squarely inside the byte range a 3/4-byte
+        // function-name prefix check would slice, which used to panic
+        // with "not a char boundary" instead of returning an error.
+        assert!(parse_color("rg€(255, 0, 0)").is_err());
+    }
+
+    #[test]
+    fn to_hex_round_trip() {
+        let c = Rgb::rgb(0.2, 0.4, 0.6);
+        let hex = c.to_hex();
+        let parsed = parse_color(&hex).unwrap();
+        assert_eq!(c.to_rgba8(), parsed.to_rgba8());
+    }
+}
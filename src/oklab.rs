@@ -0,0 +1,201 @@
+use num::traits::Float;
+
+use {Color, Rgb, Luma, Xyz, Lab, Lch, Hsv, Hsl, ColorSpace, Mix, Shade, clamp};
+
+use white_point::D65;
+
+///The Oklab color space.
+///
+///Oklab is a perceptually uniform color space, similar in spirit to
+///`Lab`, but built to make mixing and gradients look more natural. It's
+///defined as a fixed transform of D65 `Xyz`, by way of a LMS cone response
+///space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklab<T: Float = f32> {
+    ///L is the lightness of the color. 0.0 gives absolute black and 1.0
+    ///gives the full white point brightness.
+    pub l: T,
+
+    ///a goes from negative numbers for green to positive numbers for red.
+    pub a: T,
+
+    ///b goes from negative numbers for blue to positive numbers for yellow.
+    pub b: T,
+
+    ///The transparency of the color. 0.0 is completely transparent and 1.0 is
+    ///completely opaque.
+    pub alpha: T,
+}
+
+impl<T: Float> Oklab<T> {
+    ///Oklab.
+    pub fn oklab(l: T, a: T, b: T) -> Oklab<T> {
+        Oklab {
+            l: l,
+            a: a,
+            b: b,
+            alpha: T::one(),
+        }
+    }
+
+    ///Oklab and transparency.
+    pub fn oklaba(l: T, a: T, b: T, alpha: T) -> Oklab<T> {
+        Oklab {
+            l: l,
+            a: a,
+            b: b,
+            alpha: alpha,
+        }
+    }
+}
+
+impl<T: Float> ColorSpace for Oklab<T> {
+    fn is_valid(&self) -> bool {
+        self.l >= T::zero() && self.l <= T::one() && self.alpha >= T::zero() &&
+        self.alpha <= T::one()
+    }
+
+    fn clamp(&self) -> Oklab<T> {
+        let mut c = *self;
+        c.clamp_self();
+        c
+    }
+
+    fn clamp_self(&mut self) {
+        self.l = clamp(self.l, T::zero(), T::one());
+        self.alpha = clamp(self.alpha, T::zero(), T::one());
+    }
+}
+
+impl<T: Float> Mix for Oklab<T> {
+    type Scalar = T;
+
+    fn mix(&self, other: &Oklab<T>, factor: T) -> Oklab<T> {
+        let factor = clamp(factor, T::zero(), T::one());
+
+        Oklab {
+            l: self.l + factor * (other.l - self.l),
+            a: self.a + factor * (other.a - self.a),
+            b: self.b + factor * (other.b - self.b),
+            alpha: self.alpha + factor * (other.alpha - self.alpha),
+        }
+    }
+}
+
+impl<T: Float> Shade for Oklab<T> {
+    type Scalar = T;
+
+    fn lighten(&self, amount: T) -> Oklab<T> {
+        Oklab {
+            l: self.l + amount,
+            a: self.a,
+            b: self.b,
+            alpha: self.alpha,
+        }
+    }
+}
+
+impl<T: Float> Default for Oklab<T> {
+    fn default() -> Oklab<T> {
+        Oklab::oklab(T::zero(), T::zero(), T::zero())
+    }
+}
+
+from_color!(to Oklab from Rgb, Luma, Xyz, Lab, Lch, Hsv, Hsl);
+
+impl<T: Float> From<Xyz<T, D65>> for Oklab<T> {
+    fn from(xyz: Xyz<T, D65>) -> Oklab<T> {
+        let l = T::from(0.8189330101).unwrap() * xyz.x + T::from(0.3618667424).unwrap() * xyz.y -
+            T::from(0.1288597137).unwrap() * xyz.z;
+        let m = T::from(0.0329845436).unwrap() * xyz.x + T::from(0.9293118715).unwrap() * xyz.y +
+            T::from(0.0361456387).unwrap() * xyz.z;
+        let s = T::from(0.0482003018).unwrap() * xyz.x + T::from(0.2643662691).unwrap() * xyz.y +
+            T::from(0.6338517070).unwrap() * xyz.z;
+
+        let l = signed_cbrt(l);
+        let m = signed_cbrt(m);
+        let s = signed_cbrt(s);
+
+        Oklab {
+            l: T::from(0.2104542553).unwrap() * l + T::from(0.7936177850).unwrap() * m -
+                T::from(0.0040720468).unwrap() * s,
+            a: T::from(1.9779984951).unwrap() * l - T::from(2.4285922050).unwrap() * m +
+                T::from(0.4505937099).unwrap() * s,
+            b: T::from(0.0259040371).unwrap() * l + T::from(0.7827717662).unwrap() * m -
+                T::from(0.8086757660).unwrap() * s,
+            alpha: xyz.alpha,
+        }
+    }
+}
+
+impl<T: Float> From<Rgb<T>> for Oklab<T> {
+    fn from(rgb: Rgb<T>) -> Oklab<T> {
+        Xyz::from(rgb).into()
+    }
+}
+
+impl<T: Float> From<Luma<T>> for Oklab<T> {
+    fn from(luma: Luma<T>) -> Oklab<T> {
+        Xyz::from(luma).into()
+    }
+}
+
+impl<T: Float> From<Lab<T>> for Oklab<T> {
+    fn from(lab: Lab<T>) -> Oklab<T> {
+        Xyz::from(lab).into()
+    }
+}
+
+impl<T: Float> From<Lch<T>> for Oklab<T> {
+    fn from(lch: Lch<T>) -> Oklab<T> {
+        Xyz::from(lch).into()
+    }
+}
+
+impl<T: Float> From<Hsv<T>> for Oklab<T> {
+    fn from(hsv: Hsv<T>) -> Oklab<T> {
+        Xyz::from(hsv).into()
+    }
+}
+
+impl<T: Float> From<Hsl<T>> for Oklab<T> {
+    fn from(hsl: Hsl<T>) -> Oklab<T> {
+        Xyz::from(hsl).into()
+    }
+}
+
+///Takes the cube root of `t`'s absolute value and restores its sign, since `powf` isn't
+///defined for negative bases with fractional exponents.
+fn signed_cbrt<T: Float>(t: T) -> T {
+    t.signum() * t.abs().powf(T::one() / T::from(3.0).unwrap())
+}
+
+#[cfg(test)]
+mod test {
+    use super::Oklab;
+    use Xyz;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1.0e-3, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn white_is_achromatic() {
+        let xyz = Xyz::xyz(0.95047f32, 1.0, 1.08883);
+        let oklab = Oklab::from(xyz);
+
+        assert_close(oklab.l, 1.0);
+        assert_close(oklab.a, 0.0);
+        assert_close(oklab.b, 0.0);
+    }
+
+    #[test]
+    fn round_trip() {
+        let a = Xyz::xyz(0.41240f32, 0.21260, 0.01930);
+        let b: Xyz<f32> = Oklab::from(a).into();
+
+        assert_close(a.x, b.x);
+        assert_close(a.y, b.y);
+        assert_close(a.z, b.z);
+    }
+}
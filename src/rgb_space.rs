@@ -0,0 +1,210 @@
+use num::traits::Float;
+
+///A transfer function, also known as an opto-electronic (or electro-optical)
+///transfer function, describes how a gamma-encoded RGB channel relates to
+///its linear-light value.
+pub trait TransferFn<T: Float> {
+    ///Converts a gamma-encoded channel value to its linear form.
+    fn into_linear(encoded: T) -> T;
+
+    ///Converts a linear channel value to its gamma-encoded form.
+    fn from_linear(linear: T) -> T;
+}
+
+///Decodes a gamma-encoded channel using the general piecewise-gamma curve
+///shared by sRGB, Rec.709 and Display P3: a linear segment near black,
+///transitioning into a power curve for the rest of the range.
+fn piecewise_into_linear<T: Float>(c: T, a: T, b: T, k: T, g: T) -> T {
+    if c < b {
+        c / k
+    } else {
+        ((c + (a - T::one())) / a).powf(g)
+    }
+}
+
+///Encodes a linear channel using the inverse of `piecewise_into_linear`.
+fn piecewise_from_linear<T: Float>(lin: T, a: T, b: T, k: T, g: T) -> T {
+    if lin < b / k {
+        k * lin
+    } else {
+        a * lin.powf(T::one() / g) - (a - T::one())
+    }
+}
+
+///The sRGB transfer function, as used by `Srgb` and `DisplayP3`.
+pub struct SrgbTransferFn;
+
+impl<T: Float> TransferFn<T> for SrgbTransferFn {
+    fn into_linear(encoded: T) -> T {
+        let a = T::from(1.055).unwrap();
+        let b = T::from(0.04045).unwrap();
+        let k = T::from(12.92).unwrap();
+        let g = T::from(2.4).unwrap();
+        piecewise_into_linear(encoded, a, b, k, g)
+    }
+
+    fn from_linear(linear: T) -> T {
+        let a = T::from(1.055).unwrap();
+        let b = T::from(0.04045).unwrap();
+        let k = T::from(12.92).unwrap();
+        let g = T::from(2.4).unwrap();
+        piecewise_from_linear(linear, a, b, k, g)
+    }
+}
+
+///Adobe RGB's gamma of 2.19921875, expressed as a `TransferFn`.
+pub struct AdobeRgbTransferFn;
+
+impl<T: Float> TransferFn<T> for AdobeRgbTransferFn {
+    fn into_linear(encoded: T) -> T {
+        encoded.powf(T::from(2.19921875).unwrap())
+    }
+
+    fn from_linear(linear: T) -> T {
+        linear.powf(T::one() / T::from(2.19921875).unwrap())
+    }
+}
+
+///A `RgbSpace` describes an RGB gamut: a 3x3 matrix that converts its linear
+///RGB primaries to D65 XYZ, and the transfer function used to gamma-encode
+///its channels.
+pub trait RgbSpace<T: Float> {
+    ///The transfer function used to decode/encode this space's channels.
+    type TransferFn: TransferFn<T>;
+
+    ///The linear RGB -> D65 XYZ matrix, derived from this space's
+    ///chromaticity primaries and white point.
+    fn get_primaries_matrix() -> [[T; 3]; 3];
+}
+
+///The sRGB color space, as used by the web and most consumer displays.
+pub struct Srgb;
+
+impl<T: Float> RgbSpace<T> for Srgb {
+    type TransferFn = SrgbTransferFn;
+
+    fn get_primaries_matrix() -> [[T; 3]; 3] {
+        [
+            [T::from(0.4124).unwrap(), T::from(0.3576).unwrap(), T::from(0.1805).unwrap()],
+            [T::from(0.2126).unwrap(), T::from(0.7152).unwrap(), T::from(0.0722).unwrap()],
+            [T::from(0.0193).unwrap(), T::from(0.1192).unwrap(), T::from(0.9505).unwrap()],
+        ]
+    }
+}
+
+///The Rec.709 color space, used by HDTV. It shares its primaries with sRGB
+///but uses the BT.709 transfer function.
+pub struct Rec709;
+
+impl<T: Float> RgbSpace<T> for Rec709 {
+    type TransferFn = Rec709TransferFn;
+
+    fn get_primaries_matrix() -> [[T; 3]; 3] {
+        Srgb::get_primaries_matrix()
+    }
+}
+
+///The BT.709 transfer function, used by `Rec709`.
+pub struct Rec709TransferFn;
+
+impl<T: Float> TransferFn<T> for Rec709TransferFn {
+    fn into_linear(encoded: T) -> T {
+        let a = T::from(1.099).unwrap();
+        let b = T::from(0.081).unwrap();
+        let k = T::from(4.5).unwrap();
+        let g = T::from(1.0 / 0.45).unwrap();
+        piecewise_into_linear(encoded, a, b, k, g)
+    }
+
+    fn from_linear(linear: T) -> T {
+        let a = T::from(1.099).unwrap();
+        let b = T::from(0.081).unwrap();
+        let k = T::from(4.5).unwrap();
+        let g = T::from(1.0 / 0.45).unwrap();
+        piecewise_from_linear(linear, a, b, k, g)
+    }
+}
+
+///The Adobe RGB (1998) color space, a wider gamut commonly used in print and
+///photography workflows.
+pub struct AdobeRgb;
+
+impl<T: Float> RgbSpace<T> for AdobeRgb {
+    type TransferFn = AdobeRgbTransferFn;
+
+    fn get_primaries_matrix() -> [[T; 3]; 3] {
+        [
+            [T::from(0.5767309).unwrap(), T::from(0.1855540).unwrap(), T::from(0.1881852).unwrap()],
+            [T::from(0.2973769).unwrap(), T::from(0.6273491).unwrap(), T::from(0.0752741).unwrap()],
+            [T::from(0.0270343).unwrap(), T::from(0.0706872).unwrap(), T::from(0.9911085).unwrap()],
+        ]
+    }
+}
+
+///The Display P3 color space, used by many modern wide-gamut displays. It
+///shares its transfer function with sRGB.
+pub struct DisplayP3;
+
+impl<T: Float> RgbSpace<T> for DisplayP3 {
+    type TransferFn = SrgbTransferFn;
+
+    fn get_primaries_matrix() -> [[T; 3]; 3] {
+        [
+            [T::from(0.4865709).unwrap(), T::from(0.2656677).unwrap(), T::from(0.1982173).unwrap()],
+            [T::from(0.2289746).unwrap(), T::from(0.6917385).unwrap(), T::from(0.0792869).unwrap()],
+            [T::from(0.0000000).unwrap(), T::from(0.0451134).unwrap(), T::from(1.0439444).unwrap()],
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{TransferFn, SrgbTransferFn, Rec709TransferFn, AdobeRgbTransferFn};
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1.0e-6, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn srgb_endpoints() {
+        assert_close(SrgbTransferFn::into_linear(0.0), 0.0);
+        assert_close(SrgbTransferFn::into_linear(1.0), 1.0);
+        assert_close(SrgbTransferFn::from_linear(0.0), 0.0);
+        assert_close(SrgbTransferFn::from_linear(1.0), 1.0);
+    }
+
+    #[test]
+    fn srgb_round_trip() {
+        for i in 0..11 {
+            let c = i as f64 / 10.0;
+            let round_tripped = SrgbTransferFn::from_linear(SrgbTransferFn::into_linear(c));
+            assert_close(round_tripped, c);
+        }
+    }
+
+    #[test]
+    fn rec709_endpoints() {
+        assert_close(Rec709TransferFn::into_linear(0.0), 0.0);
+        assert_close(Rec709TransferFn::into_linear(1.0), 1.0);
+        assert_close(Rec709TransferFn::from_linear(0.0), 0.0);
+        assert_close(Rec709TransferFn::from_linear(1.0), 1.0);
+    }
+
+    #[test]
+    fn rec709_mid_band_round_trip() {
+        // 0.05 sits in the encoded range (0.018, 0.081) that the linear
+        // segment's breakpoint bug used to route through the power curve.
+        let encoded = 0.05;
+        let linear = Rec709TransferFn::into_linear(encoded);
+        assert_close(linear, encoded / 4.5);
+        assert_close(Rec709TransferFn::from_linear(linear), encoded);
+    }
+
+    #[test]
+    fn adobe_rgb_endpoints() {
+        assert_close(AdobeRgbTransferFn::into_linear(0.0), 0.0);
+        assert_close(AdobeRgbTransferFn::into_linear(1.0), 1.0);
+        assert_close(AdobeRgbTransferFn::from_linear(0.0), 0.0);
+        assert_close(AdobeRgbTransferFn::from_linear(1.0), 1.0);
+    }
+}
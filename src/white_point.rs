@@ -0,0 +1,43 @@
+use num::traits::Float;
+
+///A white point that defines the reference white used by a standard
+///illuminant and observer combination.
+///
+///Every `Xyz` value is defined relative to some white point. Converting
+///between two `Xyz` values that use different white points requires a
+///chromatic adaptation transform, such as the Bradford transform
+///implemented by `Xyz::adapt_from`.
+pub trait WhitePoint<T: Float> {
+    ///Get the XYZ tristimulus values of the white point, normalized so that
+    ///`y` is 1.0.
+    fn get_xyz() -> (T, T, T);
+}
+
+macro_rules! white_point {
+    ($name:ident, $x:expr, $y:expr, $z:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct $name;
+
+        impl<T: Float> WhitePoint<T> for $name {
+            fn get_xyz() -> (T, T, T) {
+                (T::from($x).unwrap(), T::from($y).unwrap(), T::from($z).unwrap())
+            }
+        }
+    };
+}
+
+white_point!(D65, 0.95047, 1.0, 1.08883,
+    "CIE Standard Illuminant D65, 2° standard observer. This is the white \
+     point assumed by sRGB and most web colors.");
+
+white_point!(D50, 0.96422, 1.0, 0.82521,
+    "CIE Standard Illuminant D50, 2° standard observer. Commonly used by \
+     print and prepress color workflows.");
+
+white_point!(A, 1.09850, 1.0, 0.35585,
+    "CIE Standard Illuminant A, 2° standard observer. Represents typical \
+     tungsten-filament lighting.");
+
+white_point!(E, 1.0, 1.0, 1.0,
+    "The equal-energy illuminant, where X = Y = Z.");
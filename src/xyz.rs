@@ -1,10 +1,12 @@
 use num::traits::Float;
 
 use std::ops::{Add, Sub, Mul, Div};
+use std::marker::PhantomData;
 
-use {Color, Rgb, Luma, Lab, Lch, Hsv, Hsl, ColorSpace, Mix, Shade, clamp};
+use {Color, Rgb, Luma, Lab, Lch, Hsv, Hsl, Oklab, ColorSpace, Mix, Shade, clamp};
 
-use tristimulus::{X_N, Y_N, Z_N};
+use white_point::{WhitePoint, D65};
+use rgb_space::{RgbSpace, Srgb, TransferFn};
 
 ///The CIE 1931 XYZ color space with an alpha component.
 ///
@@ -13,11 +15,13 @@ use tristimulus::{X_N, Y_N, Z_N};
 ///converting from one color space to an other, and requires a standard
 ///illuminant and a standard observer to be defined.
 ///
-///Conversions and operations on this color space assumes the CIE Standard
-///Illuminant D65 as the white point, and the 2° standard colorimetric
-///observer.
+///Conversions and operations on this color space are performed relative to
+///a white point `Wp`, which defaults to the CIE Standard Illuminant D65 and
+///the 2° standard colorimetric observer. Use `adapt_from`/`adapt_to` to move
+///a color between white points with the Bradford chromatic adaptation
+///transform.
 #[derive(Clone, Copy, Debug, PartialEq)]
-pub struct Xyz<T: Float = f32> {
+pub struct Xyz<T: Float = f32, Wp: WhitePoint<T> = D65> {
     ///X is the scale of what can be seen as a response curve for the cone
     ///cells in the human eye. It goes from 0.0 to 1.0.
     pub x: T,
@@ -32,38 +36,152 @@ pub struct Xyz<T: Float = f32> {
     ///The transparency of the color. 0.0 is completely transparent and 1.0 is
     ///completely opaque.
     pub alpha: T,
+
+    white_point: PhantomData<Wp>,
 }
 
-impl<T: Float> Xyz<T> {
+impl<T: Float, Wp: WhitePoint<T>> Xyz<T, Wp> {
     ///CIE XYZ.
-    pub fn xyz(x: T, y: T, z: T) -> Xyz<T> {
+    pub fn xyz(x: T, y: T, z: T) -> Xyz<T, Wp> {
         Xyz {
             x: x,
             y: y,
             z: z,
             alpha: T::one(),
+            white_point: PhantomData,
         }
     }
 
     ///CIE XYZ and transparency.
-    pub fn xyza(x: T, y: T, z: T, alpha: T) -> Xyz<T> {
+    pub fn xyza(x: T, y: T, z: T, alpha: T) -> Xyz<T, Wp> {
         Xyz {
             x: x,
             y: y,
             z: z,
             alpha: alpha,
+            white_point: PhantomData,
+        }
+    }
+
+    ///Adapts `color`, which is relative to the white point `Swp`, to this
+    ///color's white point `Wp`, using the Bradford chromatic adaptation
+    ///transform.
+    pub fn adapt_from<Swp: WhitePoint<T>>(color: Xyz<T, Swp>) -> Xyz<T, Wp> {
+        if let Some(m) = bradford_matrix::<T, Swp, Wp>() {
+            Xyz {
+                x: m[0][0] * color.x + m[0][1] * color.y + m[0][2] * color.z,
+                y: m[1][0] * color.x + m[1][1] * color.y + m[1][2] * color.z,
+                z: m[2][0] * color.x + m[2][1] * color.y + m[2][2] * color.z,
+                alpha: color.alpha,
+                white_point: PhantomData,
+            }
+        } else {
+            Xyz {
+                x: color.x,
+                y: color.y,
+                z: color.z,
+                alpha: color.alpha,
+                white_point: PhantomData,
+            }
+        }
+    }
+
+    ///Adapts this color, which is relative to the white point `Wp`, to the
+    ///white point `Dwp`, using the Bradford chromatic adaptation transform.
+    pub fn adapt_to<Dwp: WhitePoint<T>>(&self) -> Xyz<T, Dwp> {
+        Xyz::adapt_from(*self)
+    }
+}
+
+///The Bradford cone-response matrix, shared by every adaptation between two
+///white points.
+fn bradford_m<T: Float>() -> [[T; 3]; 3] {
+    [
+        [T::from(0.8951).unwrap(), T::from(0.2664).unwrap(), T::from(-0.1614).unwrap()],
+        [T::from(-0.7502).unwrap(), T::from(1.7135).unwrap(), T::from(0.0367).unwrap()],
+        [T::from(0.0389).unwrap(), T::from(-0.0685).unwrap(), T::from(1.0296).unwrap()],
+    ]
+}
+
+fn mat3_mul_vec3<T: Float>(m: &[[T; 3]; 3], v: (T, T, T)) -> (T, T, T) {
+    (
+        m[0][0] * v.0 + m[0][1] * v.1 + m[0][2] * v.2,
+        m[1][0] * v.0 + m[1][1] * v.1 + m[1][2] * v.2,
+        m[2][0] * v.0 + m[2][1] * v.1 + m[2][2] * v.2,
+    )
+}
+
+fn mat3_mul<T: Float>(a: &[[T; 3]; 3], b: &[[T; 3]; 3]) -> [[T; 3]; 3] {
+    let mut out = [[T::zero(); 3]; 3];
+    for i in 0..3 {
+        for j in 0..3 {
+            out[i][j] = a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
         }
     }
+    out
 }
 
-impl<T: Float> ColorSpace for Xyz<T> {
+///Inverts a 3x3 matrix. The Bradford matrix is well conditioned, so this is
+///only ever used on it and its products.
+fn mat3_inverse<T: Float>(m: &[[T; 3]; 3]) -> [[T; 3]; 3] {
+    let det = m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+    let inv_det = T::one() / det;
+
+    [
+        [
+            (m[1][1] * m[2][2] - m[1][2] * m[2][1]) * inv_det,
+            (m[0][2] * m[2][1] - m[0][1] * m[2][2]) * inv_det,
+            (m[0][1] * m[1][2] - m[0][2] * m[1][1]) * inv_det,
+        ],
+        [
+            (m[1][2] * m[2][0] - m[1][0] * m[2][2]) * inv_det,
+            (m[0][0] * m[2][2] - m[0][2] * m[2][0]) * inv_det,
+            (m[0][2] * m[1][0] - m[0][0] * m[1][2]) * inv_det,
+        ],
+        [
+            (m[1][0] * m[2][1] - m[1][1] * m[2][0]) * inv_det,
+            (m[0][1] * m[2][0] - m[0][0] * m[2][1]) * inv_det,
+            (m[0][0] * m[1][1] - m[0][1] * m[1][0]) * inv_det,
+        ],
+    ]
+}
+
+///Builds the full Bradford adaptation matrix `A = M⁻¹ · D · M` that maps XYZ
+///tristimulus values relative to `Swp` onto tristimulus values relative to
+///`Dwp`. Returns `None` when the two white points are the same, since the
+///adaptation is then the identity.
+fn bradford_matrix<T: Float, Swp: WhitePoint<T>, Dwp: WhitePoint<T>>() -> Option<[[T; 3]; 3]> {
+    let src = Swp::get_xyz();
+    let dst = Dwp::get_xyz();
+
+    if src == dst {
+        return None;
+    }
+
+    let m = bradford_m();
+    let (rho_s, gamma_s, beta_s) = mat3_mul_vec3(&m, src);
+    let (rho_d, gamma_d, beta_d) = mat3_mul_vec3(&m, dst);
+
+    let d = [
+        [rho_d / rho_s, T::zero(), T::zero()],
+        [T::zero(), gamma_d / gamma_s, T::zero()],
+        [T::zero(), T::zero(), beta_d / beta_s],
+    ];
+
+    let m_inv = mat3_inverse(&m);
+    Some(mat3_mul(&m_inv, &mat3_mul(&d, &m)))
+}
+
+impl<T: Float, Wp: WhitePoint<T>> ColorSpace for Xyz<T, Wp> {
     fn is_valid(&self) -> bool {
         self.x >= T::zero() && self.x <= T::one() && self.y >= T::zero() &&
         self.y <= T::one() && self.z >= T::zero() && self.z <= T::one() &&
         self.alpha >= T::zero() && self.alpha <= T::one()
     }
 
-    fn clamp(&self) -> Xyz<T> {
+    fn clamp(&self) -> Xyz<T, Wp> {
         let mut c = *self;
         c.clamp_self();
         c
@@ -77,10 +195,10 @@ impl<T: Float> ColorSpace for Xyz<T> {
     }
 }
 
-impl<T: Float> Mix for Xyz<T> {
+impl<T: Float, Wp: WhitePoint<T>> Mix for Xyz<T, Wp> {
     type Scalar = T;
 
-    fn mix(&self, other: &Xyz<T>, factor: T) -> Xyz<T> {
+    fn mix(&self, other: &Xyz<T, Wp>, factor: T) -> Xyz<T, Wp> {
         let factor = clamp(factor, T::zero(), T::one());
 
         Xyz {
@@ -88,191 +206,266 @@ impl<T: Float> Mix for Xyz<T> {
             y: self.y + factor * (other.y - self.y),
             z: self.z + factor * (other.z - self.z),
             alpha: self.alpha + factor * (other.alpha - self.alpha),
+            white_point: PhantomData,
         }
     }
 }
 
-impl<T: Float> Shade for Xyz<T> {
+impl<T: Float, Wp: WhitePoint<T>> Shade for Xyz<T, Wp> {
     type Scalar = T;
 
-    fn lighten(&self, amount: T) -> Xyz<T> {
+    fn lighten(&self, amount: T) -> Xyz<T, Wp> {
         Xyz {
             x: self.x,
             y: self.y + amount,
             z: self.z,
             alpha: self.alpha,
+            white_point: PhantomData,
         }
     }
 }
 
-impl<T: Float> Default for Xyz<T> {
-    fn default() -> Xyz<T> {
+impl<T: Float, Wp: WhitePoint<T>> Default for Xyz<T, Wp> {
+    fn default() -> Xyz<T, Wp> {
         Xyz::xyz(T::zero(), T::zero(), T::zero())
     }
 }
 
-impl<T: Float> Add<Xyz<T>> for Xyz<T> {
-    type Output = Xyz<T>;
+impl<T: Float, Wp: WhitePoint<T>> Add<Xyz<T, Wp>> for Xyz<T, Wp> {
+    type Output = Xyz<T, Wp>;
 
-    fn add(self, other: Xyz<T>) -> Xyz<T> {
+    fn add(self, other: Xyz<T, Wp>) -> Xyz<T, Wp> {
         Xyz {
             x: self.x + other.x,
             y: self.y + other.y,
             z: self.z + other.z,
             alpha: self.alpha + other.alpha,
+            white_point: PhantomData,
         }
     }
 }
 
-impl<T: Float> Add<T> for Xyz<T> {
-    type Output = Xyz<T>;
+impl<T: Float, Wp: WhitePoint<T>> Add<T> for Xyz<T, Wp> {
+    type Output = Xyz<T, Wp>;
 
-    fn add(self, c: T) -> Xyz<T> {
+    fn add(self, c: T) -> Xyz<T, Wp> {
         Xyz {
             x: self.x + c,
             y: self.y + c,
             z: self.z + c,
             alpha: self.alpha + c,
+            white_point: PhantomData,
         }
     }
 }
 
-impl<T: Float> Sub<Xyz<T>> for Xyz<T> {
-    type Output = Xyz<T>;
+impl<T: Float, Wp: WhitePoint<T>> Sub<Xyz<T, Wp>> for Xyz<T, Wp> {
+    type Output = Xyz<T, Wp>;
 
-    fn sub(self, other: Xyz<T>) -> Xyz<T> {
+    fn sub(self, other: Xyz<T, Wp>) -> Xyz<T, Wp> {
         Xyz {
             x: self.x - other.x,
             y: self.y - other.y,
             z: self.z - other.z,
             alpha: self.alpha - other.alpha,
+            white_point: PhantomData,
         }
     }
 }
 
-impl<T: Float> Sub<T> for Xyz<T> {
-    type Output = Xyz<T>;
+impl<T: Float, Wp: WhitePoint<T>> Sub<T> for Xyz<T, Wp> {
+    type Output = Xyz<T, Wp>;
 
-    fn sub(self, c: T) -> Xyz<T> {
+    fn sub(self, c: T) -> Xyz<T, Wp> {
         Xyz {
             x: self.x - c,
             y: self.y - c,
             z: self.z - c,
             alpha: self.alpha - c,
+            white_point: PhantomData,
         }
     }
 }
 
-impl<T: Float> Mul<Xyz<T>> for Xyz<T> {
-    type Output = Xyz<T>;
+impl<T: Float, Wp: WhitePoint<T>> Mul<Xyz<T, Wp>> for Xyz<T, Wp> {
+    type Output = Xyz<T, Wp>;
 
-    fn mul(self, other: Xyz<T>) -> Xyz<T> {
+    fn mul(self, other: Xyz<T, Wp>) -> Xyz<T, Wp> {
         Xyz {
             x: self.x * other.x,
             y: self.y * other.y,
             z: self.z * other.z,
             alpha: self.alpha * other.alpha,
+            white_point: PhantomData,
         }
     }
 }
 
-impl<T: Float> Mul<T> for Xyz<T> {
-    type Output = Xyz<T>;
+impl<T: Float, Wp: WhitePoint<T>> Mul<T> for Xyz<T, Wp> {
+    type Output = Xyz<T, Wp>;
 
-    fn mul(self, c: T) -> Xyz<T> {
+    fn mul(self, c: T) -> Xyz<T, Wp> {
         Xyz {
             x: self.x * c,
             y: self.y * c,
             z: self.z * c,
             alpha: self.alpha * c,
+            white_point: PhantomData,
         }
     }
 }
 
-impl<T: Float> Div<Xyz<T>> for Xyz<T> {
-    type Output = Xyz<T>;
+impl<T: Float, Wp: WhitePoint<T>> Div<Xyz<T, Wp>> for Xyz<T, Wp> {
+    type Output = Xyz<T, Wp>;
 
-    fn div(self, other: Xyz<T>) -> Xyz<T> {
+    fn div(self, other: Xyz<T, Wp>) -> Xyz<T, Wp> {
         Xyz {
             x: self.x / other.x,
             y: self.y / other.y,
             z: self.z / other.z,
             alpha: self.alpha / other.alpha,
+            white_point: PhantomData,
         }
     }
 }
 
-impl<T: Float> Div<T> for Xyz<T> {
-    type Output = Xyz<T>;
+impl<T: Float, Wp: WhitePoint<T>> Div<T> for Xyz<T, Wp> {
+    type Output = Xyz<T, Wp>;
 
-    fn div(self, c: T) -> Xyz<T> {
+    fn div(self, c: T) -> Xyz<T, Wp> {
         Xyz {
             x: self.x / c,
             y: self.y / c,
             z: self.z / c,
             alpha: self.alpha / c,
+            white_point: PhantomData,
         }
     }
 }
 
-from_color!(to Xyz from Rgb, Luma, Lab, Lch, Hsv, Hsl);
+from_color!(to Xyz from Rgb, Luma, Lab, Lch, Hsv, Hsl, Oklab);
+
+impl<T: Float> Xyz<T, D65> {
+    ///Converts gamma-encoded `red`/`green`/`blue` channels from the given
+    ///`RgbSpace` into D65 XYZ, by decoding the space's transfer function and
+    ///applying its linear RGB -> XYZ primaries matrix.
+    ///
+    ///This is the boundary where gamma-encoded values (for example, ones
+    ///parsed from a CSS color string) enter the library. `Rgb` itself
+    ///already stores linear light, so `From<Rgb<T>>` doesn't decode a
+    ///transfer function - use this instead when starting from encoded
+    ///channels.
+    pub fn from_encoded<S: RgbSpace<T>>(red: T, green: T, blue: T, alpha: T) -> Xyz<T, D65> {
+        let r = S::TransferFn::into_linear(red);
+        let g = S::TransferFn::into_linear(green);
+        let b = S::TransferFn::into_linear(blue);
+
+        let m = S::get_primaries_matrix();
 
-impl<T: Float> From<Rgb<T>> for Xyz<T> {
-    fn from(rgb: Rgb<T>) -> Xyz<T> {
         Xyz {
-            x: rgb.red * T::from(0.4124).unwrap() + rgb.green * T::from(0.3576).unwrap() + rgb.blue * T::from(0.1805).unwrap(),
-            y: rgb.red * T::from(0.2126).unwrap() + rgb.green * T::from(0.7152).unwrap() + rgb.blue * T::from(0.0722).unwrap(),
-            z: rgb.red * T::from(0.0193).unwrap() + rgb.green * T::from(0.1192).unwrap() + rgb.blue * T::from(0.9505).unwrap(),
+            x: r * m[0][0] + g * m[0][1] + b * m[0][2],
+            y: r * m[1][0] + g * m[1][1] + b * m[1][2],
+            z: r * m[2][0] + g * m[2][1] + b * m[2][2],
+            alpha: alpha,
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<T: Float> From<Rgb<T>> for Xyz<T, D65> {
+    fn from(rgb: Rgb<T>) -> Xyz<T, D65> {
+        let m = Srgb::get_primaries_matrix();
+
+        Xyz {
+            x: rgb.red * m[0][0] + rgb.green * m[0][1] + rgb.blue * m[0][2],
+            y: rgb.red * m[1][0] + rgb.green * m[1][1] + rgb.blue * m[1][2],
+            z: rgb.red * m[2][0] + rgb.green * m[2][1] + rgb.blue * m[2][2],
             alpha: rgb.alpha,
+            white_point: PhantomData,
         }
     }
 }
 
-impl<T: Float> From<Luma<T>> for Xyz<T> {
-    fn from(luma: Luma<T>) -> Xyz<T> {
+impl<T: Float> From<Luma<T>> for Xyz<T, D65> {
+    fn from(luma: Luma<T>) -> Xyz<T, D65> {
         Xyz {
             x: T::zero(),
             y: luma.luma,
             z: T::zero(),
             alpha: luma.alpha,
+            white_point: PhantomData,
         }
     }
 }
 
-impl<T: Float> From<Lab<T>> for Xyz<T> {
-    fn from(lab: Lab<T>) -> Xyz<T> {
+impl<T: Float, Wp: WhitePoint<T>> From<Lab<T>> for Xyz<T, Wp> {
+    ///Converts `lab`, which is relative to the white point `Wp`, into XYZ.
+    ///
+    ///`Lab` doesn't carry its white point in the type system, so the
+    ///illuminant has to come from the destination `Xyz<T, Wp>` instead of
+    ///being assumed to be D65; pass `Xyz::<T, D50>::from(lab)` and the like
+    ///to convert a `Lab` value defined against a different illuminant.
+    fn from(lab: Lab<T>) -> Xyz<T, Wp> {
+        let (x_n, y_n, z_n) = Wp::get_xyz();
+
         Xyz {
-            x: T::from(X_N).unwrap() * f_inv((T::one() / T::from(116.0).unwrap()) *
+            x: x_n * f_inv((T::one() / T::from(116.0).unwrap()) *
                 (lab.l * T::from(100.0).unwrap() + T::from(16.0).unwrap()) +
                 (T::one() / T::from(500.0).unwrap()) * lab.a * T::from(128.0).unwrap()),
-            y: T::from(Y_N).unwrap() * f_inv((T::one() / T::from(116.0).unwrap()) *
+            y: y_n * f_inv((T::one() / T::from(116.0).unwrap()) *
                 (lab.l * T::from(100.0).unwrap() + T::from(16.0).unwrap())),
-            z: T::from(Z_N).unwrap() * f_inv((T::one() / T::from(116.0).unwrap()) *
+            z: z_n * f_inv((T::one() / T::from(116.0).unwrap()) *
                 (lab.l * T::from(100.0).unwrap() + T::from(16.0).unwrap()) -
                 (T::one() / T::from(200.0).unwrap()) * lab.b * T::from(128.0).unwrap()),
             alpha: lab.alpha,
+            white_point: PhantomData,
         }
     }
 }
 
-impl<T: Float> From<Lch<T>> for Xyz<T> {
-    fn from(lch: Lch<T>) -> Xyz<T> {
+impl<T: Float, Wp: WhitePoint<T>> From<Lch<T>> for Xyz<T, Wp> {
+    fn from(lch: Lch<T>) -> Xyz<T, Wp> {
         Lab::from(lch).into()
     }
 }
 
-impl<T: Float> From<Hsv<T>> for Xyz<T> {
-    fn from(hsv: Hsv<T>) -> Xyz<T> {
+impl<T: Float> From<Hsv<T>> for Xyz<T, D65> {
+    fn from(hsv: Hsv<T>) -> Xyz<T, D65> {
         Rgb::from(hsv).into()
     }
 }
 
-impl<T: Float> From<Hsl<T>> for Xyz<T> {
-    fn from(hsl: Hsl<T>) -> Xyz<T> {
+impl<T: Float> From<Hsl<T>> for Xyz<T, D65> {
+    fn from(hsl: Hsl<T>) -> Xyz<T, D65> {
         Rgb::from(hsl).into()
     }
 }
 
+impl<T: Float> From<Oklab<T>> for Xyz<T, D65> {
+    fn from(oklab: Oklab<T>) -> Xyz<T, D65> {
+        let l = T::from(0.9999999985).unwrap() * oklab.l + T::from(0.3963377922).unwrap() * oklab.a +
+            T::from(0.2158037581).unwrap() * oklab.b;
+        let m = T::from(1.0000000089).unwrap() * oklab.l - T::from(0.1055613423).unwrap() * oklab.a -
+            T::from(0.0638541748).unwrap() * oklab.b;
+        let s = T::from(1.0000000547).unwrap() * oklab.l - T::from(0.0894841820).unwrap() * oklab.a -
+            T::from(1.2914855379).unwrap() * oklab.b;
+
+        let l = l * l * l;
+        let m = m * m * m;
+        let s = s * s * s;
+
+        Xyz::xyza(
+            T::from(1.2270138511).unwrap() * l - T::from(0.5577999807).unwrap() * m +
+                T::from(0.2812561490).unwrap() * s,
+            T::from(-0.0405801784).unwrap() * l + T::from(1.1122568696).unwrap() * m -
+                T::from(0.0716766787).unwrap() * s,
+            T::from(-0.0763812845).unwrap() * l - T::from(0.4214819784).unwrap() * m +
+                T::from(1.5861632204).unwrap() * s,
+            oklab.alpha,
+        )
+    }
+}
+
 
 fn f_inv<T: Float>(t: T) -> T {
     //(6/29)^2
@@ -310,4 +503,51 @@ mod test {
         let b = Xyz::xyz(0.18050, 0.07220, 0.95050);
         assert_approx_eq!(a, b, [x, y, z]);
     }
+
+    #[test]
+    fn from_rgb_is_bare_matrix() {
+        let a = Xyz::from(Rgb::linear_rgb(0.5, 0.25, 0.75));
+        let b = Xyz::xyz(
+            0.5 * 0.4124 + 0.25 * 0.3576 + 0.75 * 0.1805,
+            0.5 * 0.2126 + 0.25 * 0.7152 + 0.75 * 0.0722,
+            0.5 * 0.0193 + 0.25 * 0.1192 + 0.75 * 0.9505,
+        );
+        assert_approx_eq!(a, b, [x, y, z]);
+    }
+
+    #[test]
+    fn from_encoded_decodes_gamma() {
+        use rgb_space::{Srgb, SrgbTransferFn, TransferFn};
+
+        let a: Xyz<f64> = Xyz::from_encoded::<Srgb>(0.5, 0.5, 0.5, 1.0);
+        let linear = SrgbTransferFn::into_linear(0.5f64);
+        let b = Xyz::xyz(
+            linear * 0.4124 + linear * 0.3576 + linear * 0.1805,
+            linear * 0.2126 + linear * 0.7152 + linear * 0.0722,
+            linear * 0.0193 + linear * 0.1192 + linear * 0.9505,
+        );
+        assert_approx_eq!(a, b, [x, y, z]);
+    }
+
+    #[test]
+    fn adapt_identity() {
+        use white_point::D65;
+
+        let a = Xyz::xyz(0.41240, 0.21260, 0.01930);
+        let b: Xyz<f64, D65> = Xyz::adapt_from(a);
+        assert_approx_eq!(a, b, [x, y, z]);
+    }
+
+    #[test]
+    fn lab_white_point_is_threaded_through() {
+        use white_point::{D50, D65};
+        use Lab;
+
+        let lab = Lab::laba(1.0, 0.0, 0.0, 1.0);
+
+        let d65: Xyz<f64, D65> = Xyz::from(lab);
+        let d50: Xyz<f64, D50> = Xyz::from(lab);
+
+        assert!((d65.x - d50.x).abs() > 1.0e-4 || (d65.z - d50.z).abs() > 1.0e-4);
+    }
 }